@@ -3,7 +3,9 @@
 //! Allowes calculation of object mouvement over time based on acceleration, speed and position as well as forces.
 //! This crate is no_std and no_alloc!
 #![no_std]
+use core::f64::consts::PI;
 use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
 #[cfg(feature = "std")]
 use std::vec::Vec;
 
@@ -49,6 +51,8 @@ pub struct Object {
     pub acceleration: Places,
     /// Weight (consistent with force so normally kg and N)
     pub weight: f64,
+    /// Quadratic drag coefficient used by `step` (0.0 disables drag)
+    pub drag: f64,
 }
 impl Places {
     /// Create a place
@@ -62,6 +66,86 @@ impl Places {
         let z = z.into();
         Places { x, y, z }
     }
+    /// Dot product between two points taken as vectors.
+    /// ```
+    /// use forces::*;
+    /// let a = Places::new(1.0,2.0,3.0);
+    /// let b = Places::new(4.0,5.0,6.0);
+    /// assert_eq!(a.dot(b), 32.0);
+    /// ```
+    pub fn dot(self, other: Places) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    /// Cross product between two points taken as vectors.
+    /// ```
+    /// use forces::*;
+    /// let a = Places::new(1.0,0.0,0.0);
+    /// let b = Places::new(0.0,1.0,0.0);
+    /// let c = a.cross(b);
+    /// assert_eq!((c.x,c.y,c.z),(0.0,0.0,1.0));
+    /// ```
+    pub fn cross(self, other: Places) -> Places {
+        Places::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+    /// Length of the vector from the origin.
+    /// ```
+    /// use forces::*;
+    /// let a = Places::new(3.0,4.0,0.0);
+    /// assert_eq!(a.magnitude(), 5.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        sqrt(self.dot(*self))
+    }
+    /// Unit vector pointing in the same direction.
+    /// ```
+    /// use forces::*;
+    /// let a = Places::new(3.0,4.0,0.0);
+    /// let n = a.normalize();
+    /// assert!((n.x - 0.6).abs() < 1e-9);
+    /// assert!((n.y - 0.8).abs() < 1e-9);
+    /// assert_eq!(n.z, 0.0);
+    /// ```
+    pub fn normalize(self) -> Places {
+        self * (1.0 / self.magnitude())
+    }
+    /// Distance between two points.
+    /// ```
+    /// use forces::*;
+    /// let a = Places::new(0.0,0.0,0.0);
+    /// let b = Places::new(3.0,4.0,0.0);
+    /// assert_eq!(a.distance_to(b), 5.0);
+    /// ```
+    pub fn distance_to(&self, other: Places) -> f64 {
+        (*self - other).magnitude()
+    }
+}
+impl Add for Places {
+    type Output = Places;
+    fn add(self, other: Places) -> Places {
+        Places::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+impl Sub for Places {
+    type Output = Places;
+    fn sub(self, other: Places) -> Places {
+        Places::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+impl Mul<f64> for Places {
+    type Output = Places;
+    fn mul(self, scalar: f64) -> Places {
+        Places::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+impl Neg for Places {
+    type Output = Places;
+    fn neg(self) -> Places {
+        Places::new(-self.x, -self.y, -self.z)
+    }
 }
 impl IntoIterator for Places {
     type IntoIter = core::array::IntoIter<(Axis, f64), 3>;
@@ -80,14 +164,28 @@ impl Object {
     /// Object::new(startpoint, speed, acceleration, 30.0);
     /// ```
     pub fn new(position: Places, speed: Places, acceleration: Places, weight: f64) -> Self {
-        let weight = if !weight.is_finite() { weight } else { 1.0 };
+        let weight = if weight.is_finite() { weight } else { 1.0 };
         Object {
             position,
             speed,
             acceleration,
             weight,
+            drag: 0.0,
         }
     }
+    /// Set the quadratic drag coefficient used by `step`, builder-style.
+    /// ```
+    /// use forces::*;
+    /// let startpoint = Places::new(0.0,0.0,0.0);
+    /// let speed = Places::new(40.0,40.0,0.0);
+    /// let acceleration = Places::new(0.0,-EARTH_GRAVITY,0.0);
+    /// let object = Object::new(startpoint, speed, acceleration, 30.0).with_drag(0.2);
+    /// assert_eq!(object.drag, 0.2);
+    /// ```
+    pub fn with_drag(mut self, drag: f64) -> Self {
+        self.drag = drag;
+        self
+    }
     /// Calculation position of object after x time
     /// ```
     /// use forces::*;
@@ -98,20 +196,11 @@ impl Object {
     /// let object_t4 = object.overtime(4.0);
     /// ```
     pub fn overtime(&self, time: f64) -> Places {
-        let mut places = Places::new(0.0, 0.0, 0.0);
-        places.x = self.acceleration.x * time * time * 0.5 + self.speed.x * time + self.position.x;
-        places.y = self.acceleration.y * time * time * 0.5 + self.speed.y * time + self.position.y;
-        places.z = self.acceleration.z * time * time * 0.5 + self.speed.z * time + self.position.z;
-        places
+        self.acceleration * (time * time * 0.5) + self.speed * time + self.position
     }
     /// Same but changes the inner object
     pub fn overtime_mut(&mut self, time: f64) {
-        self.position.x =
-            self.acceleration.x * time * time * 0.5 + self.speed.x * time + self.position.x;
-        self.position.y =
-            self.acceleration.y * time * time * 0.5 + self.speed.y * time + self.position.y;
-        self.position.z =
-            self.acceleration.z * time * time * 0.5 + self.speed.z * time + self.position.z;
+        self.position = self.overtime(time);
     }
     /// When one coordinate hits zero
     /// 
@@ -177,7 +266,7 @@ impl Object {
     /// let acceleration = Places::new(0.0,-EARTH_GRAVITY,0.0);
     /// let mut object = Object::new(startpoint, speed, acceleration, 30.0);
     /// object.addforce(40.0,5.0,Axis::Y);
-    /// assert_eq!(object.speed.y,240.0);
+    /// assert_eq!(object.speed.y,46.666666666666664);
     /// ```
     pub fn addforce(&mut self, force: f64, time: f64, axis: Axis) {
         if !time.is_finite() {
@@ -207,12 +296,430 @@ impl Object {
     /// let (forcex, forcey) = object.transverseforce(20.0, 60.0);
     /// object.addforce(forcex,5.0,Axis::X);
     /// object.addforce(forcey,5.0,Axis::Y);
-    /// assert_eq!(object.speed.x,138.61432315629253);
-    /// assert_eq!(object.speed.y,56.5896132693415);
+    /// assert_eq!(object.speed.x,43.28714410520975);
+    /// assert_eq!(object.speed.y,40.55298710897805);
     /// ```
     pub fn transverseforce(&self, force: f64, degrees: f64) -> (f64, f64) {
         (force * cos(degrees/360.0),force * sin(degrees/360.0))
     }
+    /// Advance the object by one semi-implicit Euler step of size `dt`, applying the constant
+    /// `acceleration` together with quadratic drag (`F_drag = -drag * |v| * v`). Unlike
+    /// `overtime`, which assumes constant acceleration in closed form, this integrates tick by
+    /// tick, so it can model air resistance and terminal velocity.
+    /// ```
+    /// use forces::*;
+    /// let startpoint = Places::new(0.0,0.0,0.0);
+    /// let speed = Places::new(0.0,0.0,0.0);
+    /// let acceleration = Places::new(0.0,-EARTH_GRAVITY,0.0);
+    /// let mut object = Object::new(startpoint, speed, acceleration, 30.0).with_drag(0.1);
+    /// object.step(0.1);
+    /// ```
+    pub fn step(&mut self, dt: f64) {
+        let drag_force = self.speed * (-self.drag * self.speed.magnitude());
+        self.speed = self.speed + (self.acceleration + drag_force * (1.0 / self.weight)) * dt;
+        self.position = self.position + self.speed * dt;
+    }
+    /// Advance the object by one step, damping the resulting speed by a flat per-tick factor
+    /// instead of a physically-derived drag force (Minecraft-style: `v = (v + a*dt) * damping`).
+    pub fn step_damped(&mut self, dt: f64, damping: f64) {
+        self.speed = (self.speed + self.acceleration * dt) * damping;
+        self.position = self.position + self.speed * dt;
+    }
+    /// Steady-state speed at which the quadratic drag force balances the constant
+    /// acceleration, i.e. the solution of `drag*v^2 = weight*|acceleration|`.
+    /// ```
+    /// use forces::*;
+    /// let startpoint = Places::new(0.0,0.0,0.0);
+    /// let speed = Places::new(0.0,0.0,0.0);
+    /// let acceleration = Places::new(0.0,-EARTH_GRAVITY,0.0);
+    /// let object = Object::new(startpoint, speed, acceleration, 30.0).with_drag(0.1);
+    /// let terminal = object.terminal_velocity();
+    /// assert!(terminal > 0.0);
+    /// ```
+    pub fn terminal_velocity(&self) -> f64 {
+        sqrt(self.weight * self.acceleration.magnitude() / self.drag)
+    }
+    /// Resolve a force magnitude into all three axes from spherical angles, using a proper
+    /// degree-to-radian conversion. Unlike `transverseforce`, which only splits a force
+    /// between two axes, this gives the full 3D vector: `x = F·cos(el)·cos(az)`,
+    /// `y = F·cos(el)·sin(az)`, `z = F·sin(el)`.
+    /// ```
+    /// use forces::*;
+    /// let force = Object::decompose_force(10.0, 0.0, 90.0);
+    /// assert!((force.x).abs() < 1e-9);
+    /// assert!((force.y).abs() < 1e-9);
+    /// assert!((force.z - 10.0).abs() < 1e-9);
+    /// ```
+    pub fn decompose_force(force: f64, azimuth_deg: f64, elevation_deg: f64) -> Places {
+        let azimuth = azimuth_deg * PI / 180.0;
+        let elevation = elevation_deg * PI / 180.0;
+        Places::new(
+            force * cos(elevation) * cos(azimuth),
+            force * cos(elevation) * sin(azimuth),
+            force * sin(elevation),
+        )
+    }
+    /// Add a full force vector at once, for unlimited time (0s, applied to acceleration) or
+    /// over a specific amount of time (applied to speed). Mirrors `addforce`, but takes all
+    /// three axes together so angled thrust (e.g. from `decompose_force`) needs a single call.
+    /// ```
+    /// use forces::*;
+    /// let startpoint = Places::new(0.0,0.0,0.0);
+    /// let speed = Places::new(0.0,0.0,0.0);
+    /// let acceleration = Places::new(0.0,0.0,0.0);
+    /// let mut object = Object::new(startpoint, speed, acceleration, 10.0);
+    /// let force = Object::decompose_force(100.0, 0.0, 90.0);
+    /// object.addforce_vec(force, 5.0);
+    /// assert!((object.speed.z - 50.0).abs() < 1e-9);
+    /// ```
+    pub fn addforce_vec(&mut self, force: Places, time: f64) {
+        if !time.is_finite() {
+            return;
+        }
+        if time == 0.0 {
+            self.acceleration = self.acceleration + force * (1.0 / self.weight);
+        } else {
+            self.speed = self.speed + force * (time / self.weight);
+        }
+    }
+}
+/// A fixed-size collection of `Object`s that simulates mutual Newtonian gravity between
+/// them, rather than each body following its own constant acceleration.
+///
+/// `N` is the number of bodies, kept as a const generic so the whole simulation stays
+/// `no_std`/`no_alloc`. Stepping uses a velocity-Verlet (symplectic) integrator so the
+/// total energy reported by [`System::total_energy`] stays bounded over long runs.
+#[derive(Clone, Copy, Debug)]
+pub struct System<const N: usize> {
+    /// The bodies under simulation
+    pub bodies: [Object; N],
+    /// Gravitational constant used for the pairwise force
+    pub g: f64,
+    /// Softening length added to the separation distance to avoid a singularity when two bodies overlap
+    pub epsilon: f64,
+}
+impl<const N: usize> System<N> {
+    /// Create a system from a fixed array of bodies.
+    /// ```
+    /// use forces::*;
+    /// let a = Object::new(Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), 1.0);
+    /// let b = Object::new(Places::new(1.0,0.0,0.0), Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), 1.0);
+    /// let system = System::new([a, b], 1.0, 0.01);
+    /// ```
+    pub fn new(bodies: [Object; N], g: f64, epsilon: f64) -> Self {
+        System { bodies, g, epsilon }
+    }
+    /// Gravitational acceleration felt by each body due to every other body.
+    fn accelerations(&self) -> [Places; N] {
+        let mut acc = [Places::new(0.0, 0.0, 0.0); N];
+        for (i, (slot, body)) in acc.iter_mut().zip(self.bodies.iter()).enumerate() {
+            let mut a = Places::new(0.0, 0.0, 0.0);
+            for (j, other) in self.bodies.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let d = other.position - body.position;
+                let r = d.magnitude() + self.epsilon;
+                a = a + d * (self.g * other.weight / (r * r * r));
+            }
+            *slot = a;
+        }
+        acc
+    }
+    /// Advance every body by one velocity-Verlet step of size `dt`.
+    /// ```
+    /// use forces::*;
+    /// let a = Object::new(Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), 1.0);
+    /// let b = Object::new(Places::new(1.0,0.0,0.0), Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), 1.0);
+    /// let mut system = System::new([a, b], 1.0, 0.01);
+    /// system.step(0.01);
+    /// ```
+    pub fn step(&mut self, dt: f64) {
+        let previous = self.accelerations();
+        for (body, acc) in self.bodies.iter_mut().zip(previous.iter()) {
+            body.position = body.position + body.speed * dt + *acc * (0.5 * dt * dt);
+        }
+        let current = self.accelerations();
+        for (body, (prev, curr)) in self.bodies.iter_mut().zip(previous.iter().zip(current.iter())) {
+            body.speed = body.speed + (*prev + *curr) * (0.5 * dt);
+        }
+    }
+    /// Run `steps` steps of size `dt`.
+    pub fn run(&mut self, dt: f64, steps: usize) {
+        for _ in 0..steps {
+            self.step(dt);
+        }
+    }
+    /// Total energy of the system: kinetic `0.5 * m * v·v` summed over every body plus the
+    /// pairwise potential `-G*m_i*m_j/r`. Should stay roughly constant across a `run`.
+    pub fn total_energy(&self) -> f64 {
+        let mut kinetic = 0.0;
+        for body in self.bodies.iter() {
+            kinetic += 0.5 * body.weight * body.speed.dot(body.speed);
+        }
+        let mut potential = 0.0;
+        for i in 0..N {
+            for j in (i + 1)..N {
+                let r = self.bodies[i].position.distance_to(self.bodies[j].position);
+                potential += -self.g * self.bodies[i].weight * self.bodies[j].weight / r;
+            }
+        }
+        kinetic + potential
+    }
+}
+/// A rotation stored as a unit quaternion `(x, y, z, w)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Orientation {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+impl Orientation {
+    /// No rotation at all.
+    /// ```
+    /// use forces::*;
+    /// let o = Orientation::identity();
+    /// assert_eq!((o.x, o.y, o.z, o.w), (0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn identity() -> Self {
+        Orientation {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+    /// Build a rotation of `angle` radians around `axis` (normalized automatically).
+    pub fn from_axis_angle(axis: Places, angle: f64) -> Self {
+        let axis = axis.normalize();
+        let half = angle * 0.5;
+        let s = sin(half);
+        Orientation {
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+            w: cos(half),
+        }
+    }
+    /// Quaternion multiplication: apply `other`'s rotation after `self`'s.
+    fn compose(self, other: Orientation) -> Orientation {
+        Orientation {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+    /// Integrate this orientation forward by `angular_velocity` applied over `time`.
+    pub fn integrate(self, angular_velocity: Places, time: f64) -> Orientation {
+        let angle = angular_velocity.magnitude() * time;
+        if angle == 0.0 {
+            return self;
+        }
+        self.compose(Orientation::from_axis_angle(angular_velocity, angle))
+    }
+}
+/// Mirrors `Object` but adds rotational state (angular velocity, accumulated orientation
+/// and a moment of inertia), so spinning projectiles can be modeled alongside linear motion.
+#[derive(Clone, Copy, Debug)]
+pub struct RigidBody {
+    /// Linear state (position, speed, acceleration, weight)
+    pub object: Object,
+    /// Angular velocity, one component per axis
+    pub angular_velocity: Places,
+    /// Angular acceleration, one component per axis
+    pub angular_acceleration: Places,
+    /// Accumulated rotation since the body was created
+    pub orientation: Orientation,
+    /// Moment of inertia about the rotation axis
+    pub inertia: f64,
+}
+impl RigidBody {
+    /// Create a rigid body at rest (no rotation) around an existing `Object`.
+    pub fn new(object: Object, inertia: f64) -> Self {
+        RigidBody {
+            object,
+            angular_velocity: Places::new(0.0, 0.0, 0.0),
+            angular_acceleration: Places::new(0.0, 0.0, 0.0),
+            orientation: Orientation::identity(),
+            inertia,
+        }
+    }
+    /// Apply a torque for unlimited time (0s) or for a specific amount of time on a specific axis.
+    /// Mirrors `Object::addforce`, dividing by the moment of inertia instead of the mass.
+    /// ```
+    /// use forces::*;
+    /// let object = Object::new(Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), 30.0);
+    /// let mut body = RigidBody::new(object, 5.0);
+    /// body.apply_torque(10.0, 2.0, Axis::Z);
+    /// assert_eq!(body.angular_velocity.z, 4.0);
+    /// ```
+    pub fn apply_torque(&mut self, torque: f64, time: f64, axis: Axis) {
+        if !time.is_finite() {
+            return;
+        }
+        let data_to_change = match time == 0.0 {
+            true => match axis {
+                Axis::X => &mut self.angular_acceleration.x,
+                Axis::Y => &mut self.angular_acceleration.y,
+                Axis::Z => &mut self.angular_acceleration.z,
+            },
+            false => match axis {
+                Axis::X => &mut self.angular_velocity.x,
+                Axis::Y => &mut self.angular_velocity.y,
+                Axis::Z => &mut self.angular_velocity.z,
+            },
+        };
+        *data_to_change += torque / self.inertia * (if time == 0.0 { 1.0 } else { time });
+    }
+    /// Advance linear position by `time` (as `Object::overtime_mut`), then add
+    /// `angular_acceleration * time` to the angular velocity and integrate the orientation
+    /// with that updated angular velocity. The linear speed is left unchanged.
+    pub fn overtime_mut(&mut self, time: f64) {
+        self.object.overtime_mut(time);
+        self.angular_velocity = self.angular_velocity + self.angular_acceleration * time;
+        self.orientation = self.orientation.integrate(self.angular_velocity, time);
+    }
+    /// Combined linear and angular velocity, as `(linear, angular)`.
+    pub fn velocity(&self) -> (Places, Places) {
+        (self.object.speed, self.angular_velocity)
+    }
+    /// Velocity of a point offset `r` from the center of mass: `linear + angular × r`.
+    pub fn point_velocity(&self, r: Places) -> Places {
+        self.object.speed + self.angular_velocity.cross(r)
+    }
+}
+/// Trapezoidal velocity profile for a single axis: accelerate at a fixed rate, optionally
+/// cruise at a fixed speed, then decelerate back to rest exactly on the target.
+#[derive(Clone, Copy, Debug)]
+struct AxisProfile {
+    start: f64,
+    accel_time: f64,
+    cruise_time: f64,
+    peak_velocity: f64,
+}
+impl AxisProfile {
+    fn sample(&self, t: f64) -> f64 {
+        if self.peak_velocity == 0.0 {
+            return self.start;
+        }
+        let a = self.peak_velocity / self.accel_time;
+        let accel_distance = 0.5 * a * self.accel_time * self.accel_time;
+        let cruise_distance = self.peak_velocity * self.cruise_time;
+        if t <= 0.0 {
+            self.start
+        } else if t <= self.accel_time {
+            self.start + 0.5 * a * t * t
+        } else if t <= self.accel_time + self.cruise_time {
+            self.start + accel_distance + self.peak_velocity * (t - self.accel_time)
+        } else if t <= 2.0 * self.accel_time + self.cruise_time {
+            let t3 = t - self.accel_time - self.cruise_time;
+            self.start + accel_distance + cruise_distance + self.peak_velocity * t3 - 0.5 * a * t3 * t3
+        } else {
+            self.start + 2.0 * accel_distance + cruise_distance
+        }
+    }
+}
+/// Compute `(accel_time, cruise_time, peak_velocity)` for moving `distance` (signed) from rest
+/// to rest under a maximum acceleration `a_max` and maximum speed `v_max`.
+fn trapezoidal_profile(distance: f64, a_max: f64, v_max: f64) -> (f64, f64, f64) {
+    if distance == 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    let sign = if distance < 0.0 { -1.0 } else { 1.0 };
+    let d = distance.abs();
+    let full_accel_time = v_max / a_max;
+    let full_accel_distance = 0.5 * a_max * full_accel_time * full_accel_time;
+    if 2.0 * full_accel_distance >= d {
+        // Never reaches v_max: triangular profile with no cruise phase.
+        let accel_time = sqrt(d / a_max);
+        (accel_time, 0.0, sign * a_max * accel_time)
+    } else {
+        let cruise_time = (d - 2.0 * full_accel_distance) / v_max;
+        (full_accel_time, cruise_time, sign * v_max)
+    }
+}
+/// Stretch an axis' profile to take exactly `duration`, keeping `a_max` and solving for the
+/// (necessarily lower) peak velocity that still lands on `distance` with zero final velocity.
+fn synchronize(distance: f64, a_max: f64, duration: f64, profile: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (accel_time, cruise_time, _) = profile;
+    if distance == 0.0 || 2.0 * accel_time + cruise_time >= duration {
+        return profile;
+    }
+    let sign = if distance < 0.0 { -1.0 } else { 1.0 };
+    let d = distance.abs();
+    let discriminant = duration * duration - 4.0 * d / a_max;
+    let discriminant = if discriminant < 0.0 { 0.0 } else { discriminant };
+    let accel_time = (duration - sqrt(discriminant)) / 2.0;
+    let peak_velocity = sign * a_max * accel_time;
+    let cruise_time = duration - 2.0 * accel_time;
+    (accel_time, cruise_time, peak_velocity)
+}
+/// Generates a straight-line, point-to-point trajectory from an `Object`'s current position to
+/// a target `Places`, reaching it with zero final velocity. Each axis follows a trapezoidal
+/// velocity profile (accelerate, optional cruise, decelerate) and the three axes are
+/// synchronized to the slowest one so the motion stays a straight line in 3D.
+#[derive(Clone, Copy, Debug)]
+pub struct MotionGenerator {
+    x: AxisProfile,
+    y: AxisProfile,
+    z: AxisProfile,
+    /// Total duration of the maneuver
+    pub duration: f64,
+}
+impl MotionGenerator {
+    /// Plan a maneuver from `current`'s position to `goal`, bounded by `a_max` and `v_max`.
+    /// ```
+    /// use forces::*;
+    /// let object = Object::new(Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), Places::new(0.0,0.0,0.0), 1.0);
+    /// let goal = Places::new(10.0, 0.0, 0.0);
+    /// let plan = MotionGenerator::new(&object, goal, 2.0, 3.0);
+    /// let end = plan.sample(plan.duration);
+    /// assert!((end.x - 10.0).abs() < 1e-9);
+    /// ```
+    pub fn new(current: &Object, goal: Places, a_max: f64, v_max: f64) -> Self {
+        let dx = goal.x - current.position.x;
+        let dy = goal.y - current.position.y;
+        let dz = goal.z - current.position.z;
+        let px = trapezoidal_profile(dx, a_max, v_max);
+        let py = trapezoidal_profile(dy, a_max, v_max);
+        let pz = trapezoidal_profile(dz, a_max, v_max);
+        let duration = (2.0 * px.0 + px.1)
+            .max(2.0 * py.0 + py.1)
+            .max(2.0 * pz.0 + pz.1);
+        // The slowest axis is the one with the longest distance; scaling every other axis'
+        // acceleration by its share of that distance gives all axes the same phase timings.
+        let dmax = dx.abs().max(dy.abs()).max(dz.abs());
+        let px = synchronize(dx, a_max * dx.abs() / dmax, duration, px);
+        let py = synchronize(dy, a_max * dy.abs() / dmax, duration, py);
+        let pz = synchronize(dz, a_max * dz.abs() / dmax, duration, pz);
+        MotionGenerator {
+            x: AxisProfile {
+                start: current.position.x,
+                accel_time: px.0,
+                cruise_time: px.1,
+                peak_velocity: px.2,
+            },
+            y: AxisProfile {
+                start: current.position.y,
+                accel_time: py.0,
+                cruise_time: py.1,
+                peak_velocity: py.2,
+            },
+            z: AxisProfile {
+                start: current.position.z,
+                accel_time: pz.0,
+                cruise_time: pz.1,
+                peak_velocity: pz.2,
+            },
+            duration,
+        }
+    }
+    /// Position along the maneuver at time `t`, clamped to the goal once `t >= duration`.
+    pub fn sample(&self, t: f64) -> Places {
+        Places::new(self.x.sample(t), self.y.sample(t), self.z.sample(t))
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -250,4 +757,75 @@ mod tests {
         }
         assert_eq!(object.acceleration.y, -EARTH_GRAVITY);
     }
+
+    #[test]
+    fn motion_generator_synchronizes_axes_to_goal() {
+        let object = Object::new(
+            Places::new(0.0, 0.0, 0.0),
+            Places::new(0.0, 0.0, 0.0),
+            Places::new(0.0, 0.0, 0.0),
+            1.0,
+        );
+        let goal = Places::new(10.0, 2.0, 0.0);
+        let plan = MotionGenerator::new(&object, goal, 2.0, 3.0);
+        let end = plan.sample(plan.duration);
+        assert!((end.x - goal.x).abs() < 1e-6);
+        assert!((end.y - goal.y).abs() < 1e-6);
+        assert!((end.z - goal.z).abs() < 1e-6);
+    }
+
+    #[test]
+    fn system_conserves_energy_over_a_run() {
+        let a = Object::new(
+            Places::new(-1.0, 0.0, 0.0),
+            Places::new(0.0, 0.3, 0.0),
+            Places::new(0.0, 0.0, 0.0),
+            1.0,
+        );
+        let b = Object::new(
+            Places::new(1.0, 0.0, 0.0),
+            Places::new(0.0, -0.3, 0.0),
+            Places::new(0.0, 0.0, 0.0),
+            1.0,
+        );
+        let mut system = System::new([a, b], 1.0, 0.05);
+        let initial_energy = system.total_energy();
+        system.run(0.01, 200);
+        let final_energy = system.total_energy();
+        assert!((final_energy - initial_energy).abs() < initial_energy.abs() * 0.1);
+    }
+
+    #[test]
+    fn rigid_body_rotates_under_torque() {
+        let object = Object::new(
+            Places::new(0.0, 0.0, 0.0),
+            Places::new(0.0, 0.0, 0.0),
+            Places::new(0.0, 0.0, 0.0),
+            2.0,
+        );
+        let mut body = RigidBody::new(object, 1.0);
+        body.apply_torque(4.0, 0.0, Axis::Z);
+        body.overtime_mut(1.0);
+        assert_eq!(body.angular_velocity.z, 4.0);
+        assert!(body.orientation.w < 1.0);
+        let point_velocity = body.point_velocity(Places::new(1.0, 0.0, 0.0));
+        assert_eq!(point_velocity.x, 0.0);
+        assert_eq!(point_velocity.y, 4.0);
+        assert_eq!(point_velocity.z, 0.0);
+    }
+
+    #[test]
+    fn object_step_reaches_terminal_velocity() {
+        let startpoint = Places::new(0.0, 0.0, 0.0);
+        let speed = Places::new(0.0, 0.0, 0.0);
+        let acceleration = Places::new(0.0, -EARTH_GRAVITY, 0.0);
+        // weight 10.0 is preserved (not collapsed to 1.0), so both `step` and
+        // `terminal_velocity` must agree on the same non-unit mass.
+        let mut object = Object::new(startpoint, speed, acceleration, 10.0).with_drag(0.5);
+        let terminal = object.terminal_velocity();
+        for _ in 0..20000 {
+            object.step(0.01);
+        }
+        assert!((object.speed.magnitude() - terminal).abs() < 1e-6);
+    }
 }